@@ -11,11 +11,91 @@
 //! [TPM]: https://en.wikipedia.org/wiki/Trusted_Platform_Module
 
 use super::HashAlgorithm;
-use crate::data_types::PhysicalAddress;
+use crate::data_types::{Guid, PhysicalAddress};
 use crate::proto::unsafe_protocol;
+use crate::table::cfg::ConfigTableEntry;
 use crate::{Result, Status};
 use bitflags::bitflags;
-use core::mem;
+use core::marker::PhantomData;
+use core::{mem, ptr, slice};
+
+/// TPM2 algorithm identifier (`TPM_ALG_ID`) for SHA-1.
+const TPM_ALG_SHA1: u16 = 0x0004;
+
+/// TPM2 algorithm identifier (`TPM_ALG_ID`) for SHA-256.
+const TPM_ALG_SHA256: u16 = 0x000B;
+
+/// TPM2 algorithm identifier (`TPM_ALG_ID`) for SHA-384.
+const TPM_ALG_SHA384: u16 = 0x000C;
+
+/// TPM2 algorithm identifier (`TPM_ALG_ID`) for SHA-512.
+const TPM_ALG_SHA512: u16 = 0x000D;
+
+/// TPM2 algorithm identifier (`TPM_ALG_ID`) for SM3-256.
+const TPM_ALG_SM3_256: u16 = 0x0012;
+
+impl HashAlgorithm {
+    /// Convert a TPM2 algorithm identifier (`TPM_ALG_ID`) to the
+    /// corresponding `HashAlgorithm` flag.
+    ///
+    /// Returns `None` if `alg_id` isn't one of the algorithms
+    /// `HashAlgorithm` represents.
+    #[must_use]
+    pub fn from_tpm_alg_id(alg_id: u16) -> Option<Self> {
+        Some(match alg_id {
+            TPM_ALG_SHA1 => Self::SHA1,
+            TPM_ALG_SHA256 => Self::SHA256,
+            TPM_ALG_SHA384 => Self::SHA384,
+            TPM_ALG_SHA512 => Self::SHA512,
+            TPM_ALG_SM3_256 => Self::SM3_256,
+            _ => return None,
+        })
+    }
+
+    /// Convert this flag to its TPM2 algorithm identifier (`TPM_ALG_ID`).
+    ///
+    /// Returns `None` unless `self` is exactly one of the algorithms
+    /// `HashAlgorithm` represents.
+    #[must_use]
+    pub fn to_tpm_alg_id(self) -> Option<u16> {
+        Some(match self {
+            Self::SHA1 => TPM_ALG_SHA1,
+            Self::SHA256 => TPM_ALG_SHA256,
+            Self::SHA384 => TPM_ALG_SHA384,
+            Self::SHA512 => TPM_ALG_SHA512,
+            Self::SM3_256 => TPM_ALG_SM3_256,
+            _ => return None,
+        })
+    }
+
+    /// Digest size, in bytes, produced by this algorithm.
+    ///
+    /// Returns `None` unless `self` is exactly one of the algorithms
+    /// `HashAlgorithm` represents.
+    #[must_use]
+    pub fn digest_size(self) -> Option<usize> {
+        Some(match self {
+            Self::SHA1 => 20,
+            Self::SHA256 | Self::SM3_256 => 32,
+            Self::SHA384 => 48,
+            Self::SHA512 => 64,
+            _ => return None,
+        })
+    }
+
+    /// Iterate over the individual algorithms set in this bitmap.
+    pub fn algorithms(self) -> impl Iterator<Item = Self> {
+        [
+            Self::SHA1,
+            Self::SHA256,
+            Self::SHA384,
+            Self::SHA512,
+            Self::SM3_256,
+        ]
+        .into_iter()
+        .filter(move |&alg| self.contains(alg))
+    }
+}
 
 /// Version information.
 ///
@@ -44,6 +124,338 @@ bitflags! {
     }
 }
 
+/// Maximum number of hash algorithms tracked for a single event-log entry.
+///
+/// The TCG EFI Protocol Specification does not impose a hard limit, but
+/// firmware only logs a digest for each algorithm actually active on the
+/// TPM, and [`HashAlgorithm`] currently defines five of them.
+const MAX_DIGEST_ALGORITHMS: usize = 5;
+
+/// Cursor for walking the firmware-owned, self-describing memory backing
+/// the event log and the TCG2 final events table. The referenced memory
+/// isn't a Rust allocation, so it's read through a raw address rather
+/// than a slice with a statically-known length.
+#[derive(Clone, Copy)]
+struct EventLogCursor<'a> {
+    addr: u64,
+    _marker: PhantomData<&'a [u8]>,
+}
+
+impl<'a> EventLogCursor<'a> {
+    fn new(addr: u64) -> Self {
+        Self {
+            addr,
+            _marker: PhantomData,
+        }
+    }
+
+    fn addr(&self) -> u64 {
+        self.addr
+    }
+
+    unsafe fn read_u16(&mut self) -> u16 {
+        let val = ptr::read_unaligned(self.addr as *const u16);
+        self.addr += mem::size_of::<u16>() as u64;
+        val
+    }
+
+    unsafe fn read_u32(&mut self) -> u32 {
+        let val = ptr::read_unaligned(self.addr as *const u32);
+        self.addr += mem::size_of::<u32>() as u64;
+        val
+    }
+
+    unsafe fn read_u64(&mut self) -> u64 {
+        let val = ptr::read_unaligned(self.addr as *const u64);
+        self.addr += mem::size_of::<u64>() as u64;
+        val
+    }
+
+    unsafe fn read_bytes(&mut self, len: usize) -> &'a [u8] {
+        let bytes = slice::from_raw_parts(self.addr as *const u8, len);
+        self.addr += len as u64;
+        bytes
+    }
+
+    unsafe fn skip(&mut self, len: usize) {
+        self.addr += len as u64;
+    }
+}
+
+/// Digest size (in bytes) advertised for each active hash algorithm, as
+/// read from the `TCG_EfiSpecIdEvent` at the start of a crypto-agile event
+/// log.
+#[derive(Clone, Copy, Debug, Default)]
+struct DigestSizes {
+    entries: [(u16, u16); MAX_DIGEST_ALGORITHMS],
+    len: usize,
+}
+
+impl DigestSizes {
+    fn size_of(&self, algorithm_id: u16) -> Option<u16> {
+        self.entries[..self.len]
+            .iter()
+            .find(|(id, _)| *id == algorithm_id)
+            .map(|(_, size)| *size)
+    }
+}
+
+/// A single digest value within an [`EventLogEntry`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Digest<'a> {
+    /// TCG-assigned algorithm identifier (`TPM_ALG_ID`) for this digest.
+    pub algorithm_id: u16,
+
+    /// Digest bytes. The length depends on `algorithm_id`.
+    pub digest: &'a [u8],
+}
+
+/// One entry (`TCG_PCR_EVENT2`) in a crypto-agile event log.
+#[derive(Clone, Copy, Debug)]
+pub struct EventLogEntry<'a> {
+    pcr_index: u32,
+    event_type: u32,
+    digests: [Digest<'a>; MAX_DIGEST_ALGORITHMS],
+    num_digests: usize,
+    event_data: &'a [u8],
+}
+
+impl<'a> EventLogEntry<'a> {
+    /// PCR index the event was measured into.
+    #[must_use]
+    pub fn pcr_index(&self) -> u32 {
+        self.pcr_index
+    }
+
+    /// Type of event. See the TCG PC Client Platform Firmware Profile
+    /// specification for the list of defined event types.
+    #[must_use]
+    pub fn event_type(&self) -> u32 {
+        self.event_type
+    }
+
+    /// Digests of the measured data, one per active hash algorithm.
+    #[must_use]
+    pub fn digests(&self) -> &[Digest<'a>] {
+        &self.digests[..self.num_digests]
+    }
+
+    /// Event data associated with the measurement. The format depends on
+    /// [`event_type`][Self::event_type].
+    #[must_use]
+    pub fn event_data(&self) -> &'a [u8] {
+        self.event_data
+    }
+}
+
+/// Parse the legacy `TCG_PCR_EVENT` / `TCG_EfiSpecIdEvent` that every
+/// crypto-agile event log begins with. This is needed to learn the digest
+/// size used for each active hash algorithm before the `TCG_PCR_EVENT2`
+/// entries that follow can be parsed.
+///
+/// Returns the digest sizes and the address of the first `TCG_PCR_EVENT2`
+/// entry.
+unsafe fn parse_spec_id_event(addr: u64) -> (DigestSizes, u64) {
+    let mut cursor = EventLogCursor::new(addr);
+
+    // TCG_PCR_EVENT, which always uses the legacy SHA-1 layout, even at
+    // the start of a crypto-agile log.
+    let _pcr_index = cursor.read_u32();
+    let _event_type = cursor.read_u32();
+    cursor.skip(20); // SHA-1 digest
+    let event_size = cursor.read_u32();
+    let event_start = cursor.addr();
+
+    // TCG_EfiSpecIdEvent.
+    cursor.skip(16); // signature
+    cursor.skip(4); // platformClass
+    cursor.skip(1); // specVersionMinor
+    cursor.skip(1); // specVersionMajor
+    cursor.skip(1); // specErrata
+    cursor.skip(1); // uintnSize
+    let number_of_algorithms = cursor.read_u32() as usize;
+
+    let mut sizes = DigestSizes {
+        len: number_of_algorithms.min(MAX_DIGEST_ALGORITHMS),
+        ..DigestSizes::default()
+    };
+    for entry in &mut sizes.entries[..sizes.len] {
+        let algorithm_id = cursor.read_u16();
+        let digest_size = cursor.read_u16();
+        *entry = (algorithm_id, digest_size);
+    }
+    // Skip any algorithms beyond what we have room to record; this
+    // shouldn't happen in practice, but don't let it corrupt parsing.
+    if number_of_algorithms > sizes.len {
+        cursor.skip((number_of_algorithms - sizes.len) * 4);
+    }
+    // The rest of the event (vendorInfo) isn't needed, and the next entry
+    // is found via the self-described `event_size` regardless.
+
+    (sizes, event_start + u64::from(event_size))
+}
+
+/// Parse one `TCG_PCR_EVENT2` entry at `addr`.
+///
+/// Returns the entry and the address of the next one.
+unsafe fn parse_pcr_event2<'a>(addr: u64, digest_sizes: &DigestSizes) -> (EventLogEntry<'a>, u64) {
+    let mut cursor = EventLogCursor::new(addr);
+
+    let pcr_index = cursor.read_u32();
+    let event_type = cursor.read_u32();
+
+    let digest_count = cursor.read_u32() as usize;
+    let parsed_count = digest_count.min(MAX_DIGEST_ALGORITHMS);
+    let mut digests = [Digest::default(); MAX_DIGEST_ALGORITHMS];
+    for digest in &mut digests[..parsed_count] {
+        let algorithm_id = cursor.read_u16();
+        let size = digest_sizes.size_of(algorithm_id).unwrap_or(0) as usize;
+        let bytes = cursor.read_bytes(size);
+        *digest = Digest {
+            algorithm_id,
+            digest: bytes,
+        };
+    }
+    // A well-behaved TPM won't report more digests than we have room for,
+    // but skip over any extras rather than mis-parsing the rest of the log.
+    for _ in parsed_count..digest_count {
+        let algorithm_id = cursor.read_u16();
+        let size = digest_sizes.size_of(algorithm_id).unwrap_or(0) as usize;
+        cursor.skip(size);
+    }
+
+    let event_size = cursor.read_u32() as usize;
+    let event_data = cursor.read_bytes(event_size);
+
+    let entry = EventLogEntry {
+        pcr_index,
+        event_type,
+        digests,
+        num_digests: parsed_count,
+        event_data,
+    };
+
+    (entry, cursor.addr())
+}
+
+/// Iterator over the entries in an [`EventLog`].
+#[derive(Clone)]
+pub struct EventLogIter<'a> {
+    cursor: u64,
+    last_entry: u64,
+    done: bool,
+    digest_sizes: DigestSizes,
+    _marker: PhantomData<&'a [u8]>,
+}
+
+impl<'a> EventLogIter<'a> {
+    fn new(digest_sizes: DigestSizes, first_entry: u64, last_entry: u64) -> Self {
+        Self {
+            cursor: first_entry,
+            last_entry,
+            done: false,
+            digest_sizes,
+            _marker: PhantomData,
+        }
+    }
+
+    fn empty() -> Self {
+        Self {
+            cursor: 0,
+            last_entry: 0,
+            done: true,
+            digest_sizes: DigestSizes::default(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> Iterator for EventLogIter<'a> {
+    type Item = EventLogEntry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // `cursor > last_entry` means the log has no `TCG_PCR_EVENT2`
+        // entries at all (firmware reported `event_log_last_entry` before
+        // the first entry), so nothing should be parsed.
+        if self.done || self.cursor > self.last_entry {
+            return None;
+        }
+        let is_last_entry = self.cursor == self.last_entry;
+
+        // Safety: `cursor` starts at the first entry of a log returned by
+        // `Tcg::get_event_log` (or the final events table), and each step
+        // advances by the self-described size of the entry just read, so
+        // it always lands on the start of a valid entry.
+        let (entry, next) = unsafe { parse_pcr_event2(self.cursor, &self.digest_sizes) };
+
+        self.done = is_last_entry;
+        self.cursor = next;
+
+        Some(entry)
+    }
+}
+
+/// A parsed event log, as returned by [`Tcg::get_event_log`].
+///
+/// Only the crypto-agile ([`EventLogFormat::TCG_2`]) format is supported.
+#[derive(Clone)]
+pub struct EventLog<'a> {
+    truncated: bool,
+    last_entry: u64,
+    // Digest sizes and the address of the first `TCG_PCR_EVENT2` entry, or
+    // `None` if the log is empty.
+    entries: Option<(DigestSizes, u64)>,
+    _marker: PhantomData<&'a [u8]>,
+}
+
+impl<'a> EventLog<'a> {
+    /// # Safety
+    ///
+    /// `location` and `last_entry` must be the output of a successful call
+    /// to [`Tcg::get_event_log`] with [`EventLogFormat::TCG_2`], and the
+    /// memory they reference must remain valid for `'a`.
+    unsafe fn new(location: u64, last_entry: u64, truncated: bool) -> Self {
+        let entries = if location == 0 {
+            None
+        } else {
+            Some(parse_spec_id_event(location))
+        };
+
+        Self {
+            truncated,
+            last_entry,
+            entries,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Whether the firmware ran out of space and had to drop log entries.
+    #[must_use]
+    pub fn truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Iterate over the entries in the log.
+    #[must_use]
+    pub fn iter(&self) -> EventLogIter<'a> {
+        match self.entries {
+            Some((digest_sizes, first_entry)) => {
+                EventLogIter::new(digest_sizes, first_entry, self.last_entry)
+            }
+            None => EventLogIter::empty(),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &EventLog<'a> {
+    type Item = EventLogEntry<'a>;
+    type IntoIter = EventLogIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 /// Information about the protocol and the TPM device.
 ///
 /// Layout compatible with the C type `EFI_TCG2_BOOT_SERVICE_CAPABILITY`.
@@ -131,6 +543,99 @@ bitflags! {
     }
 }
 
+/// Highest valid PCR index. The TCG EFI Protocol Specification defines 24
+/// PCRs (indices 0 through 23).
+const MAX_PCR_INDEX: u32 = 23;
+
+/// `HeaderSize` of the `EFI_TCG2_EVENT_HEADER` embedded in every
+/// [`PcrEventInputs`], i.e. `size_of::<u32>() + size_of::<u16>() + 2 *
+/// size_of::<u32>()`.
+const EVENT_HEADER_SIZE: u32 = 14;
+
+/// Version of the `EFI_TCG2_EVENT_HEADER` layout used by [`PcrEventInputs`].
+const EVENT_HEADER_VERSION: u16 = 1;
+
+/// Inputs for [`Tcg::hash_log_extend_event`], corresponding to the C type
+/// `EFI_TCG2_EVENT`.
+///
+/// This owns a single contiguous buffer holding the packed `Size` and
+/// `Header` fields followed by the event data, matching the C layout so it
+/// can be passed to the firmware as one pointer.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct PcrEventInputs(alloc::vec::Vec<u8>);
+
+#[cfg(feature = "alloc")]
+impl PcrEventInputs {
+    /// Create new event inputs that will measure `event_data` into
+    /// `pcr_index` as an event of type `event_type`.
+    ///
+    /// `event_type` is one of the `EV_*` constants defined by the TCG PC
+    /// Client Platform Firmware Profile specification.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Status::INVALID_PARAMETER`] if `pcr_index` is greater than
+    /// 23, the highest valid PCR index.
+    pub fn new(pcr_index: u32, event_type: u32, event_data: &[u8]) -> Result<Self> {
+        if pcr_index > MAX_PCR_INDEX {
+            return Err(Status::INVALID_PARAMETER.into());
+        }
+
+        let size =
+            u32::try_from(mem::size_of::<u32>() + EVENT_HEADER_SIZE as usize + event_data.len())
+                .map_err(|_| Status::INVALID_PARAMETER)?;
+
+        let mut buf = alloc::vec::Vec::with_capacity(size as usize);
+        buf.extend_from_slice(&size.to_ne_bytes());
+        buf.extend_from_slice(&EVENT_HEADER_SIZE.to_ne_bytes());
+        buf.extend_from_slice(&EVENT_HEADER_VERSION.to_ne_bytes());
+        buf.extend_from_slice(&pcr_index.to_ne_bytes());
+        buf.extend_from_slice(&event_type.to_ne_bytes());
+        buf.extend_from_slice(event_data);
+
+        Ok(Self(buf))
+    }
+
+    fn as_ptr(&self) -> *const () {
+        self.0.as_ptr().cast()
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod pcr_event_inputs_tests {
+    use super::*;
+
+    #[test]
+    fn encodes_efi_tcg2_event_header() {
+        let event_data = [0xaa, 0xbb, 0xcc];
+        let inputs = PcrEventInputs::new(4, 0x0000_000D, &event_data).unwrap();
+
+        // `Size` covers itself, the header, and the event data.
+        let expected_size = mem::size_of::<u32>() + EVENT_HEADER_SIZE as usize + event_data.len();
+        assert_eq!(
+            u32::from_ne_bytes(inputs.0[0..4].try_into().unwrap()),
+            expected_size as u32
+        );
+        // `HeaderSize` is `size_of::<EFI_TCG2_EVENT_HEADER>()`: HeaderSize
+        // (u32) + HeaderVersion (u16) + PCRIndex (u32) + EventType (u32).
+        assert_eq!(u32::from_ne_bytes(inputs.0[4..8].try_into().unwrap()), 14);
+        assert_eq!(u16::from_ne_bytes(inputs.0[8..10].try_into().unwrap()), 1);
+        assert_eq!(u32::from_ne_bytes(inputs.0[10..14].try_into().unwrap()), 4);
+        assert_eq!(
+            u32::from_ne_bytes(inputs.0[14..18].try_into().unwrap()),
+            0x0000_000D
+        );
+        assert_eq!(&inputs.0[18..], &event_data);
+        assert_eq!(inputs.0.len(), expected_size);
+    }
+
+    #[test]
+    fn rejects_out_of_range_pcr_index() {
+        assert!(PcrEventInputs::new(24, 0, &[]).is_err());
+    }
+}
+
 /// Protocol for interacting with TPM devices.
 ///
 /// This protocol can be used for interacting with older TPM 1.1/1.2
@@ -184,6 +689,183 @@ pub struct Tcg {
     ) -> Status,
 }
 
+/// TPM2 command tag for a command with no attached sessions
+/// (`TPM_ST_NO_SESSIONS`).
+const TPM_ST_NO_SESSIONS: u16 = 0x8001;
+
+/// TPM2 command code for `TPM2_GetCapability`.
+const TPM2_CC_GET_CAPABILITY: u32 = 0x0000_017A;
+
+/// `TPM_CAP_TPM_PROPERTIES`: selects the TPM's fixed and variable
+/// properties.
+const TPM2_CAP_TPM_PROPERTIES: u32 = 0x0000_0006;
+
+/// `TPM_CAP_PCRS`: selects the TPM's supported and active PCR banks.
+const TPM2_CAP_PCRS: u32 = 0x0000_0005;
+
+/// `TPM_PT_MANUFACTURER`.
+const TPM_PT_MANUFACTURER: u32 = 0x0000_0105;
+
+/// `TPM_PT_MAX_COMMAND_SIZE`.
+const TPM_PT_MAX_COMMAND_SIZE: u32 = 0x0000_011E;
+
+/// `TPM_PT_MAX_RESPONSE_SIZE`.
+const TPM_PT_MAX_RESPONSE_SIZE: u32 = 0x0000_011F;
+
+/// `TPM_PT_PCR_COUNT`.
+const TPM_PT_PCR_COUNT: u32 = 0x0000_0112;
+
+/// Number of `TPM_PT_*` properties requested by
+/// [`Tcg::get_tpm_properties`]. The TPM returns properties in ascending
+/// order starting from the first requested one, so this must cover the
+/// full `TPM_PT_MANUFACTURER..=TPM_PT_MAX_RESPONSE_SIZE` range.
+const TPM_PROPERTY_COUNT: u32 = 32;
+
+/// Build a `TPM2_GetCapability` command: the command header followed by
+/// the `capability`, `property`, and `propertyCount` parameters.
+fn get_capability_command(capability: u32, property: u32, property_count: u32) -> [u8; 22] {
+    const COMMAND_SIZE: u32 = 22;
+
+    let mut cmd = [0u8; COMMAND_SIZE as usize];
+    cmd[0..2].copy_from_slice(&TPM_ST_NO_SESSIONS.to_be_bytes());
+    cmd[2..6].copy_from_slice(&COMMAND_SIZE.to_be_bytes());
+    cmd[6..10].copy_from_slice(&TPM2_CC_GET_CAPABILITY.to_be_bytes());
+    cmd[10..14].copy_from_slice(&capability.to_be_bytes());
+    cmd[14..18].copy_from_slice(&property.to_be_bytes());
+    cmd[18..22].copy_from_slice(&property_count.to_be_bytes());
+    cmd
+}
+
+/// Parse a TPM2 response header (`tag`, `responseSize`, `responseCode`),
+/// returning the remaining response parameters.
+fn parse_response_header(response: &[u8]) -> Result<&[u8]> {
+    if response.len() < 10 {
+        return Err(Status::DEVICE_ERROR.into());
+    }
+
+    let response_code = u32::from_be_bytes(response[6..10].try_into().unwrap());
+    if response_code != 0 {
+        return Err(Status::DEVICE_ERROR.into());
+    }
+
+    Ok(&response[10..])
+}
+
+#[cfg(test)]
+mod tpm2_command_tests {
+    use super::*;
+
+    #[test]
+    fn capability_and_property_constants_match_the_tpm2_spec() {
+        // Pin the on-the-wire values so a future edit can't quietly drift
+        // back to the wrong capability/property codes.
+        assert_eq!(TPM2_CAP_TPM_PROPERTIES, 0x0000_0006);
+        assert_eq!(TPM2_CAP_PCRS, 0x0000_0005);
+        assert_eq!(TPM_PT_MANUFACTURER, 0x0000_0105);
+        assert_eq!(TPM_PT_MAX_COMMAND_SIZE, 0x0000_011E);
+        assert_eq!(TPM_PT_MAX_RESPONSE_SIZE, 0x0000_011F);
+        assert_eq!(TPM_PT_PCR_COUNT, 0x0000_0112);
+    }
+
+    #[test]
+    fn builds_get_capability_command() {
+        let cmd = get_capability_command(TPM2_CAP_PCRS, 1, MAX_DIGEST_ALGORITHMS as u32);
+
+        assert_eq!(
+            u16::from_be_bytes(cmd[0..2].try_into().unwrap()),
+            TPM_ST_NO_SESSIONS
+        );
+        assert_eq!(u32::from_be_bytes(cmd[2..6].try_into().unwrap()), 22);
+        assert_eq!(
+            u32::from_be_bytes(cmd[6..10].try_into().unwrap()),
+            TPM2_CC_GET_CAPABILITY
+        );
+        assert_eq!(
+            u32::from_be_bytes(cmd[10..14].try_into().unwrap()),
+            TPM2_CAP_PCRS
+        );
+        assert_eq!(u32::from_be_bytes(cmd[14..18].try_into().unwrap()), 1);
+        assert_eq!(
+            u32::from_be_bytes(cmd[18..22].try_into().unwrap()),
+            MAX_DIGEST_ALGORITHMS as u32
+        );
+    }
+
+    #[test]
+    fn parses_successful_response_header() {
+        let mut response = [0u8; 14];
+        response[0..2].copy_from_slice(&TPM_ST_NO_SESSIONS.to_be_bytes());
+        response[2..6].copy_from_slice(&14u32.to_be_bytes());
+        response[6..10].copy_from_slice(&0u32.to_be_bytes());
+        response[10..14].copy_from_slice(&[0xde, 0xad, 0xbe, 0xef]);
+
+        let params = parse_response_header(&response).unwrap();
+        assert_eq!(params, &[0xde, 0xad, 0xbe, 0xef]);
+    }
+
+    #[test]
+    fn rejects_nonzero_response_code() {
+        let mut response = [0u8; 10];
+        response[6..10].copy_from_slice(&1u32.to_be_bytes());
+
+        assert!(parse_response_header(&response).is_err());
+    }
+
+    #[test]
+    fn rejects_short_response() {
+        assert!(parse_response_header(&[0u8; 9]).is_err());
+    }
+}
+
+/// TPM properties read via `TPM2_GetCapability` / `TPM_CAP_TPM_PROPERTIES`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Tpm2Properties {
+    /// Maximum size (in bytes) of a command that can be sent to the TPM.
+    pub max_command_size: u32,
+
+    /// Maximum size (in bytes) of a response the TPM can return.
+    pub max_response_size: u32,
+
+    /// TPM manufacturer ID. See the [TCG Vendor ID registry].
+    ///
+    /// [TCG Vendor ID registry]: https://trustedcomputinggroup.org/resource/vendor-id-registry/
+    pub manufacturer_id: u32,
+
+    /// Number of PCRs implemented by the TPM.
+    pub pcr_count: u32,
+}
+
+/// The set of PCRs allocated to one hash algorithm bank, as reported by
+/// `TPM2_GetCapability` / `TPM_CAP_PCRS` (one entry of a
+/// `TPML_PCR_SELECTION`).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct PcrSelection {
+    /// TPM2 algorithm identifier (`TPM_ALG_ID`) for this bank.
+    pub algorithm_id: u16,
+
+    /// Bitmap of selected PCRs, least-significant bit first. Only the
+    /// first `size` bytes are meaningful.
+    pub pcr_select: [u8; 3],
+
+    /// Number of valid bytes in `pcr_select`.
+    pub size: u8,
+}
+
+/// The PCR banks reported by [`Tcg::get_pcr_banks`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PcrBanks {
+    entries: [PcrSelection; MAX_DIGEST_ALGORITHMS],
+    len: usize,
+}
+
+impl PcrBanks {
+    /// The reported PCR selections, one per hash algorithm bank.
+    #[must_use]
+    pub fn iter(&self) -> impl Iterator<Item = &PcrSelection> {
+        self.entries[..self.len].iter()
+    }
+}
+
 impl Tcg {
     /// Get information about the protocol and TPM device.
     pub fn get_capability(&mut self) -> Result<BootServiceCapability> {
@@ -191,6 +873,188 @@ impl Tcg {
         unsafe { (self.get_capability)(self, &mut capability).into_with_val(|| capability) }
     }
 
+    /// Get the event log recorded so far, parsed according to `format`.
+    ///
+    /// Only the crypto-agile [`EventLogFormat::TCG_2`] format is supported.
+    /// [`EventLog::iter`] yields a borrowed view of each entry without
+    /// requiring unsafe pointer arithmetic from the caller.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Status::INVALID_PARAMETER`] if `format` is not exactly
+    /// [`EventLogFormat::TCG_2`]; the legacy SHA-1 log has a different,
+    /// unparsed entry layout.
+    pub fn get_event_log(&mut self, format: EventLogFormat) -> Result<EventLog<'_>> {
+        if format != EventLogFormat::TCG_2 {
+            return Err(Status::INVALID_PARAMETER.into());
+        }
+
+        let mut location: PhysicalAddress = 0;
+        let mut last_entry: PhysicalAddress = 0;
+        let mut truncated: u8 = 0;
+
+        let status = unsafe {
+            (self.get_event_log)(self, format, &mut location, &mut last_entry, &mut truncated)
+        };
+
+        status.into_with_val(|| unsafe { EventLog::new(location, last_entry, truncated != 0) })
+    }
+
+    /// Hash `data_to_hash`, extend the PCR named by `event`, and (unless
+    /// [`HashLogExtendEventFlags::EFI_TCG2_EXTEND_ONLY`] is set) log the
+    /// event.
+    ///
+    /// If [`HashLogExtendEventFlags::PE_COFF_IMAGE`] is set, `data_to_hash`
+    /// is instead interpreted as the base of a loaded PE/COFF image, which
+    /// the firmware measures according to the PE/COFF measurement rules
+    /// rather than hashing the slice verbatim.
+    #[cfg(feature = "alloc")]
+    pub fn hash_log_extend_event(
+        &mut self,
+        flags: HashLogExtendEventFlags,
+        data_to_hash: &[u8],
+        event: &PcrEventInputs,
+    ) -> Result {
+        let status = unsafe {
+            (self.hash_log_extend_event)(
+                self,
+                flags,
+                data_to_hash.as_ptr() as PhysicalAddress,
+                data_to_hash.len() as u64,
+                event.as_ptr(),
+            )
+        };
+
+        status.into()
+    }
+
+    /// Send a raw TPM command in `input` and receive the TPM's response into
+    /// `output`.
+    ///
+    /// `input` must be no larger than the TPM's maximum command size and
+    /// `output` no larger than its maximum response size, both reported by
+    /// [`Tcg::get_capability`].
+    ///
+    /// Returns the number of bytes of `output` that were filled in by the
+    /// TPM's response.
+    pub fn submit_command(&mut self, input: &[u8], output: &mut [u8]) -> Result<usize> {
+        let capability = self.get_capability()?;
+
+        if input.len() > usize::from(capability.max_command_size)
+            || output.len() > usize::from(capability.max_response_size)
+        {
+            return Err(Status::INVALID_PARAMETER.into());
+        }
+
+        let input_size = u32::try_from(input.len()).map_err(|_| Status::INVALID_PARAMETER)?;
+        let output_size = u32::try_from(output.len()).map_err(|_| Status::INVALID_PARAMETER)?;
+
+        let status = unsafe {
+            (self.submit_command)(
+                self,
+                input_size,
+                input.as_ptr(),
+                output_size,
+                output.as_mut_ptr(),
+            )
+        };
+
+        status.into_with_val(|| {
+            if output.len() < 6 {
+                return output.len();
+            }
+            let response_size = u32::from_be_bytes(output[2..6].try_into().unwrap()) as usize;
+            response_size.min(output.len())
+        })
+    }
+
+    /// Query TPM properties via `TPM2_GetCapability` /
+    /// `TPM_CAP_TPM_PROPERTIES`.
+    pub fn get_tpm_properties(&mut self) -> Result<Tpm2Properties> {
+        let cmd = get_capability_command(
+            TPM2_CAP_TPM_PROPERTIES,
+            TPM_PT_MANUFACTURER,
+            TPM_PROPERTY_COUNT,
+        );
+
+        let mut response = [0u8; 512];
+        let len = self.submit_command(&cmd, &mut response)?;
+        let params = parse_response_header(&response[..len])?;
+
+        // TPMS_CAPABILITY_DATA: capability:u32, TPML_TAGGED_TPM_PROPERTY {
+        // count:u32, TPMS_TAGGED_PROPERTY { property:u32, value:u32 } ... }
+        if params.len() < 9 {
+            return Err(Status::DEVICE_ERROR.into());
+        }
+        let count = u32::from_be_bytes(params[5..9].try_into().unwrap()) as usize;
+
+        let mut properties = Tpm2Properties::default();
+        let mut offset = 9;
+        for _ in 0..count {
+            let Some(entry) = params.get(offset..offset + 8) else {
+                break;
+            };
+            let property = u32::from_be_bytes(entry[0..4].try_into().unwrap());
+            let value = u32::from_be_bytes(entry[4..8].try_into().unwrap());
+            match property {
+                TPM_PT_MANUFACTURER => properties.manufacturer_id = value,
+                TPM_PT_MAX_COMMAND_SIZE => properties.max_command_size = value,
+                TPM_PT_MAX_RESPONSE_SIZE => properties.max_response_size = value,
+                TPM_PT_PCR_COUNT => properties.pcr_count = value,
+                _ => {}
+            }
+            offset += 8;
+        }
+
+        Ok(properties)
+    }
+
+    /// Query the PCR banks via `TPM2_GetCapability` / `TPM_CAP_PCRS`: the
+    /// hash algorithms for which PCRs are allocated.
+    pub fn get_pcr_banks(&mut self) -> Result<PcrBanks> {
+        let cmd = get_capability_command(TPM2_CAP_PCRS, 0, MAX_DIGEST_ALGORITHMS as u32);
+
+        let mut response = [0u8; 512];
+        let len = self.submit_command(&cmd, &mut response)?;
+        let params = parse_response_header(&response[..len])?;
+
+        // TPMS_CAPABILITY_DATA: capability:u32, TPML_PCR_SELECTION {
+        // count:u32, TPMS_PCR_SELECTION { hash:u16, sizeofSelect:u8,
+        // pcrSelect:[u8; sizeofSelect] } ... }
+        if params.len() < 9 {
+            return Err(Status::DEVICE_ERROR.into());
+        }
+        let count = u32::from_be_bytes(params[5..9].try_into().unwrap()) as usize;
+
+        let mut banks = PcrBanks::default();
+        let mut offset = 9;
+        for _ in 0..count.min(MAX_DIGEST_ALGORITHMS) {
+            let Some(header) = params.get(offset..offset + 3) else {
+                break;
+            };
+            let algorithm_id = u16::from_be_bytes(header[0..2].try_into().unwrap());
+            let size = header[2];
+            offset += 3;
+
+            let Some(select) = params.get(offset..offset + size as usize) else {
+                break;
+            };
+            let mut pcr_select = [0u8; 3];
+            let copy_len = (size as usize).min(pcr_select.len());
+            pcr_select[..copy_len].copy_from_slice(&select[..copy_len]);
+            offset += size as usize;
+
+            banks.entries[banks.len] = PcrSelection {
+                algorithm_id,
+                pcr_select,
+                size: copy_len as u8,
+            };
+            banks.len += 1;
+        }
+
+        Ok(banks)
+    }
+
     /// Get a bitmap of the active PCR banks. Each bank corresponds to a hash
     /// algorithm.
     pub fn get_active_pcr_banks(&mut self) -> Result<HashAlgorithm> {
@@ -234,3 +1098,241 @@ impl Tcg {
         })
     }
 }
+
+/// GUID of the `EFI_TCG2_FINAL_EVENTS_TABLE` configuration table entry.
+const TCG2_FINAL_EVENTS_TABLE_GUID: Guid = Guid::from_values(
+    0x1e2e_d096,
+    0x30e2,
+    0x4254,
+    [0xbd, 0x89, 0x86, 0x3b, 0xbe, 0xf8, 0x23, 0x25],
+);
+
+/// Iterator over the entries in a [`FinalEventsTable`].
+#[derive(Clone)]
+pub struct FinalEventsIter<'a> {
+    cursor: u64,
+    remaining: u64,
+    digest_sizes: DigestSizes,
+    _marker: PhantomData<&'a [u8]>,
+}
+
+impl<'a> Iterator for FinalEventsIter<'a> {
+    type Item = EventLogEntry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        // Safety: the final events table has the same `TCG_PCR_EVENT2`
+        // layout as a crypto-agile event log, and `cursor` starts at the
+        // first of `remaining` entries known to follow it. See also
+        // `EventLogIter::next`.
+        let (entry, next) = unsafe { parse_pcr_event2(self.cursor, &self.digest_sizes) };
+
+        self.cursor = next;
+        self.remaining -= 1;
+
+        Some(entry)
+    }
+}
+
+/// The `EFI_TCG2_FINAL_EVENTS_TABLE`, a UEFI configuration table firmware
+/// uses to record crypto-agile events measured after `ExitBootServices`,
+/// which are therefore missing from the log returned by
+/// [`Tcg::get_event_log`].
+///
+/// This reuses the same entry type and digest layout as [`EventLog`]; the
+/// only structural difference is that the final events table has no
+/// leading `TCG_EfiSpecIdEvent`, so the caller supplies the active hash
+/// algorithms instead, and the digest size of each is derived from them.
+#[derive(Clone)]
+pub struct FinalEventsTable<'a> {
+    version: u64,
+    number_of_events: u64,
+    first_entry: u64,
+    digest_sizes: DigestSizes,
+    _marker: PhantomData<&'a [u8]>,
+}
+
+impl<'a> FinalEventsTable<'a> {
+    /// Locate and parse the `EFI_TCG2_FINAL_EVENTS_TABLE` among
+    /// `config_table` (as returned by `SystemTable::config_table`).
+    ///
+    /// `active_pcr_banks` (as reported by
+    /// [`BootServiceCapability::active_pcr_banks`]) is used to look up the
+    /// digest size for each hash algorithm recorded in the table.
+    ///
+    /// Returns `None` if firmware hasn't published the table, which is
+    /// expected before `ExitBootServices` has been called.
+    #[must_use]
+    pub fn locate(
+        config_table: &[ConfigTableEntry],
+        active_pcr_banks: HashAlgorithm,
+    ) -> Option<Self> {
+        let entry = config_table
+            .iter()
+            .find(|entry| entry.guid == TCG2_FINAL_EVENTS_TABLE_GUID)?;
+        if entry.address.is_null() {
+            return None;
+        }
+
+        let mut cursor = EventLogCursor::new(entry.address as u64);
+
+        // Safety: `entry.address` points at a firmware-published
+        // `EFI_TCG2_FINAL_EVENTS_TABLE`, which starts with the `Version`
+        // and `NumberOfEvents` header fields read here.
+        let (version, number_of_events) = unsafe { (cursor.read_u64(), cursor.read_u64()) };
+        let first_entry = cursor.addr();
+
+        let mut digest_sizes = DigestSizes::default();
+        for algorithm in active_pcr_banks.algorithms() {
+            if digest_sizes.len >= MAX_DIGEST_ALGORITHMS {
+                break;
+            }
+            if let (Some(algorithm_id), Some(size)) =
+                (algorithm.to_tpm_alg_id(), algorithm.digest_size())
+            {
+                digest_sizes.entries[digest_sizes.len] = (algorithm_id, size as u16);
+                digest_sizes.len += 1;
+            }
+        }
+
+        Some(Self {
+            version,
+            number_of_events,
+            first_entry,
+            digest_sizes,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Version of the final events table structure. Currently always 1.
+    #[must_use]
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Number of events recorded in the table.
+    #[must_use]
+    pub fn number_of_events(&self) -> u64 {
+        self.number_of_events
+    }
+
+    /// Iterate over the recorded events.
+    #[must_use]
+    pub fn iter(&self) -> FinalEventsIter<'a> {
+        FinalEventsIter {
+            cursor: self.first_entry,
+            remaining: self.number_of_events,
+            digest_sizes: self.digest_sizes,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> IntoIterator for &FinalEventsTable<'a> {
+    type Item = EventLogEntry<'a>;
+    type IntoIter = FinalEventsIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    /// Build a synthetic crypto-agile event log containing a single
+    /// `TCG_PCR_EVENT2` entry (preceded by the mandatory
+    /// `TCG_PCR_EVENT` / `TCG_EfiSpecIdEvent` header), and return the log
+    /// bytes along with the offset of the `TCG_PCR_EVENT2` entry.
+    fn synthetic_log(
+        pcr_index: u32,
+        event_type: u32,
+        digest: &[u8; 32],
+        event_data: &[u8],
+    ) -> (Vec<u8>, usize) {
+        let mut buf = Vec::new();
+
+        // TCG_PCR_EVENT (legacy SHA-1 layout).
+        buf.extend_from_slice(&0u32.to_ne_bytes()); // PCRIndex
+        buf.extend_from_slice(&3u32.to_ne_bytes()); // EventType (EV_NO_ACTION)
+        buf.extend_from_slice(&[0u8; 20]); // SHA-1 digest
+
+        // TCG_EfiSpecIdEvent, one algorithm (SHA-256), no vendor info.
+        let mut spec_id_event = Vec::new();
+        spec_id_event.extend_from_slice(b"Spec ID Event03\0"); // signature
+        spec_id_event.extend_from_slice(&0u32.to_ne_bytes()); // platformClass
+        spec_id_event.push(0); // specVersionMinor
+        spec_id_event.push(2); // specVersionMajor
+        spec_id_event.push(0); // specErrata
+        spec_id_event.push(2); // uintnSize
+        spec_id_event.extend_from_slice(&1u32.to_ne_bytes()); // numberOfAlgorithms
+        spec_id_event.extend_from_slice(&TPM_ALG_SHA256.to_ne_bytes());
+        spec_id_event.extend_from_slice(&32u16.to_ne_bytes()); // digest size
+        spec_id_event.push(0); // vendorInfoSize
+
+        buf.extend_from_slice(&(spec_id_event.len() as u32).to_ne_bytes()); // EventSize
+        buf.extend_from_slice(&spec_id_event);
+
+        let pcr_event2_offset = buf.len();
+
+        // TCG_PCR_EVENT2.
+        buf.extend_from_slice(&pcr_index.to_ne_bytes());
+        buf.extend_from_slice(&event_type.to_ne_bytes());
+        buf.extend_from_slice(&1u32.to_ne_bytes()); // digest count
+        buf.extend_from_slice(&TPM_ALG_SHA256.to_ne_bytes());
+        buf.extend_from_slice(digest);
+        buf.extend_from_slice(&(event_data.len() as u32).to_ne_bytes());
+        buf.extend_from_slice(event_data);
+
+        (buf, pcr_event2_offset)
+    }
+
+    #[test]
+    fn parses_spec_id_event_digest_sizes() {
+        let (buf, pcr_event2_offset) = synthetic_log(0, 0, &[0xab; 32], b"");
+        let addr = buf.as_ptr() as u64;
+
+        let (sizes, next) = unsafe { parse_spec_id_event(addr) };
+
+        assert_eq!(next, addr + pcr_event2_offset as u64);
+        assert_eq!(sizes.size_of(TPM_ALG_SHA256), Some(32));
+        assert_eq!(sizes.size_of(TPM_ALG_SHA1), None);
+    }
+
+    #[test]
+    fn iterates_single_entry_log() {
+        let digest = [0x42; 32];
+        let (buf, pcr_event2_offset) = synthetic_log(7, 0x0000_000D, &digest, b"hello");
+        let addr = buf.as_ptr() as u64;
+        let first_entry = addr + pcr_event2_offset as u64;
+
+        let (sizes, next) = unsafe { parse_spec_id_event(addr) };
+        assert_eq!(next, first_entry);
+
+        let mut iter = EventLogIter::new(sizes, first_entry, first_entry);
+
+        let entry = iter.next().expect("expected one entry");
+        assert_eq!(entry.pcr_index(), 7);
+        assert_eq!(entry.event_type(), 0x0000_000D);
+        assert_eq!(entry.event_data(), b"hello");
+        assert_eq!(entry.digests().len(), 1);
+        assert_eq!(entry.digests()[0].algorithm_id, TPM_ALG_SHA256);
+        assert_eq!(entry.digests()[0].digest, &digest);
+
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn empty_log_yields_no_entries() {
+        // `first_entry > last_entry` is how an empty log is represented:
+        // firmware reports `event_log_last_entry` before the start of the
+        // (nonexistent) first `TCG_PCR_EVENT2` entry.
+        let mut iter = EventLogIter::new(DigestSizes::default(), 100, 0);
+        assert!(iter.next().is_none());
+    }
+}